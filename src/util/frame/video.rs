@@ -1,9 +1,11 @@
 use std::mem;
 use std::slice;
+use std::marker::PhantomData;
 use std::ops::{Deref, DerefMut};
 
-use libc::c_int;
+use libc::{c_int, EAGAIN};
 use ffi::*;
+use ::Error;
 use ::Rational;
 use ::util::format;
 use ::util::chroma;
@@ -258,6 +260,32 @@ impl Video {
 
 		result
 	}
+
+	pub fn readable(&self) -> VideoFrameRef<Readable> {
+		VideoFrameRef {
+			ptr:     self.as_ptr() as *mut AVFrame,
+			format:  self.format(),
+			width:   self.width(),
+			height:  self.height(),
+			_marker: PhantomData,
+		}
+	}
+
+	pub fn writable(&mut self) -> Result<VideoFrameRef<Writable>, Error> {
+		unsafe {
+			if av_frame_is_writable(self.as_mut_ptr()) == 0 {
+				return Err(Error::from(AVERROR(EAGAIN)));
+			}
+		}
+
+		Ok(VideoFrameRef {
+			ptr:     self.as_mut_ptr(),
+			format:  self.format(),
+			width:   self.width(),
+			height:  self.height(),
+			_marker: PhantomData,
+		})
+	}
 }
 
 impl Deref for Video {
@@ -290,6 +318,120 @@ impl Clone for Video {
 	}
 }
 
+/// Marker type for a [`VideoFrameRef`] that only grants read access.
+pub enum Readable { }
+
+/// Marker type for a [`VideoFrameRef`] that grants write access, handed out
+/// only when the backing buffer is not shared.
+pub enum Writable { }
+
+/// A bounds-checked, stride-correct view into a [`Video`] frame's planes.
+///
+/// Unlike the raw `plane`/`data` accessors, the plane dimensions are derived
+/// from the pixel-format descriptor, so chroma subsampling (e.g. the
+/// `height / 2` U/V planes of `YUV420P`) is respected and the returned slices
+/// never extend past the allocated buffer.
+pub struct VideoFrameRef<'a, T> {
+	ptr:     *mut AVFrame,
+	format:  format::Pixel,
+	width:   u32,
+	height:  u32,
+	_marker: PhantomData<&'a T>,
+}
+
+impl<'a, T> VideoFrameRef<'a, T> {
+	pub fn planes(&self) -> usize {
+		for i in 0 .. 8 {
+			unsafe {
+				if (*self.ptr).linesize[i] == 0 {
+					return i;
+				}
+			}
+		}
+
+		8
+	}
+
+	pub fn stride(&self, index: usize) -> usize {
+		if index >= self.planes() {
+			panic!("out of bounds");
+		}
+
+		unsafe {
+			(*self.ptr).linesize[index] as usize
+		}
+	}
+
+	pub fn plane_width(&self, index: usize) -> u32 {
+		if index >= self.planes() {
+			panic!("out of bounds");
+		}
+
+		let (shift, _) = chroma_shift(self.format);
+
+		if index == 1 || index == 2 {
+			(self.width + (1 << shift) - 1) >> shift
+		}
+		else {
+			self.width
+		}
+	}
+
+	pub fn plane_height(&self, index: usize) -> u32 {
+		if index >= self.planes() {
+			panic!("out of bounds");
+		}
+
+		let (_, shift) = chroma_shift(self.format);
+
+		if index == 1 || index == 2 {
+			(self.height + (1 << shift) - 1) >> shift
+		}
+		else {
+			self.height
+		}
+	}
+
+	pub fn plane<C: Component>(&self, index: usize) -> &[C] {
+		if !<C as Component>::is_valid(self.format) {
+			panic!("unsupported type");
+		}
+
+		unsafe {
+			slice::from_raw_parts(
+				mem::transmute((*self.ptr).data[index]),
+				self.plane_height(index) as usize * self.stride(index) / mem::size_of::<C>())
+		}
+	}
+}
+
+impl<'a> VideoFrameRef<'a, Writable> {
+	pub fn plane_mut<C: Component>(&mut self, index: usize) -> &mut [C] {
+		if !<C as Component>::is_valid(self.format) {
+			panic!("unsupported type");
+		}
+
+		unsafe {
+			slice::from_raw_parts_mut(
+				mem::transmute((*self.ptr).data[index]),
+				self.plane_height(index) as usize * self.stride(index) / mem::size_of::<C>())
+		}
+	}
+}
+
+fn chroma_shift(format: format::Pixel) -> (u8, u8) {
+	unsafe {
+		let desc = av_pix_fmt_desc_get(format.into());
+
+		if desc.is_null() {
+			(0, 0)
+		}
+		else {
+			((*desc).log2_chroma_w, (*desc).log2_chroma_h)
+		}
+	}
+}
+
 pub trait Component {
 	fn is_valid(format: format::Pixel) -> bool;
 }